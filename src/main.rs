@@ -1,5 +1,12 @@
 use bevy::prelude::*;
 use bevy::math::vec3;
+use bevy::render::camera::{ClearColorConfig, RenderTarget};
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::render::view::RenderLayers;
+use bevy::window::RequestRedraw;
+use bevy::winit::WinitSettings;
+use bevy_egui::{egui, EguiContexts, EguiPlugin};
 use bevy_pancam::{DirectionKeys, PanCam, PanCamPlugin};
 use noise::{NoiseFn, Simplex, Perlin};
 use serde::{Deserialize, Serialize};
@@ -8,7 +15,65 @@ use std::collections::{HashMap, HashSet};
 #[derive(Default, Resource)]
 struct TileMap {
     tiles: HashMap<(i32, i32), TileType>,
-    spawned: HashSet<(i32, i32)>,
+    /// Spawned tile entity per coordinate, for O(1) lookup instead of
+    /// scanning every `SerializableTile` to find the one under the cursor.
+    entities: HashMap<(i32, i32), Entity>,
+    /// Coordinates the user explicitly painted, as opposed to tiles that
+    /// only exist because the noise generator filled them in. Saving the
+    /// map only persists these, instead of dumping the infinite world.
+    painted: HashSet<(i32, i32)>,
+}
+
+const MAP_SAVE_PATH: &str = "map.ron";
+
+const CHUNK_SIZE: i32 = 16;
+const CHUNK_RADIUS: i32 = 4;
+
+/// Tile entities belonging to one loaded chunk, so unloading it is a
+/// direct despawn of exactly those entities rather than a linear scan.
+#[derive(Default)]
+struct ChunkState {
+    entities: Vec<Entity>,
+}
+
+/// Tracks which chunks are currently spawned and which chunk the camera
+/// was in last frame, so `update_tiles` can skip all work on frames where
+/// the camera hasn't crossed into a new chunk.
+#[derive(Resource, Default)]
+struct ChunkMap {
+    loaded: HashMap<(i32, i32), ChunkState>,
+    last_camera_chunk: Option<(i32, i32)>,
+}
+
+fn chunk_coord(tile: (i32, i32)) -> (i32, i32) {
+    (tile.0.div_euclid(CHUNK_SIZE), tile.1.div_euclid(CHUNK_SIZE))
+}
+
+fn chunk_tile_coords(chunk: (i32, i32)) -> impl Iterator<Item = (i32, i32)> {
+    let base_x = chunk.0 * CHUNK_SIZE;
+    let base_y = chunk.1 * CHUNK_SIZE;
+    (0..CHUNK_SIZE).flat_map(move |dy| (0..CHUNK_SIZE).map(move |dx| (base_x + dx, base_y + dy)))
+}
+
+/// Chunk coordinates within `radius` of `center`. Chunks are always a plain
+/// square grid regardless of `TileShape`: `chunk_coord` derives them with
+/// `div_euclid(CHUNK_SIZE)` on tile coordinates, and `CHUNK_SIZE` is even, so
+/// hex mode's half-tile row offset cancels out completely at chunk
+/// granularity — there is no hex lattice to flood here. The parity-dependent
+/// hex math belongs at the individual-tile level instead, in
+/// `tile_to_world`/`world_to_tile`.
+fn visible_chunk_coords(center: (i32, i32), radius: i32) -> HashSet<(i32, i32)> {
+    ((center.1 - radius)..=(center.1 + radius))
+        .flat_map(|cy| ((center.0 - radius)..=(center.0 + radius)).map(move |cx| (cx, cy)))
+        .collect()
+}
+
+/// The `Simplex`/`Perlin` generators, built once at startup instead of
+/// being reconstructed every frame inside the tile-streaming loop.
+#[derive(Resource)]
+struct NoiseGenerators {
+    simplex: Simplex,
+    perlin: Perlin,
 }
 
 #[derive(Resource, PartialEq, Debug)]
@@ -17,9 +82,64 @@ enum EditorMode {
     Paint,
 }
 
+/// Runtime-toggleable power setting, applied to `WinitSettings` by
+/// `apply_power_mode` instead of choosing `WinitSettings::desktop_app()` vs
+/// `::game()` once at startup.
+#[derive(Resource, PartialEq, Debug)]
+enum PowerMode {
+    /// `WinitSettings::desktop_app()`: only redraws in response to input/OS
+    /// events, for idle editing sessions.
+    Reactive,
+    /// `WinitSettings::game()`: redraws continuously, for smooth painting
+    /// or panning sessions where reactive mode's redraw gaps are noticeable.
+    Continuous,
+}
+
 #[derive(Resource)]
 struct SelectedTileType(TileType);
 
+#[derive(Resource, PartialEq, Clone, Copy, Debug)]
+enum TileShape {
+    Square,
+    Hex,
+}
+
+/// Thumbnail render targets for the egui tile palette, one per `TileType`.
+///
+/// Each entry pairs a tile with the `Handle<Image>` a dedicated camera
+/// renders it into; `texture_ids` is filled in lazily the first time the
+/// palette UI runs, once an `EguiContexts` is available to register them.
+#[derive(Resource)]
+struct TilePalette {
+    thumbnails: Vec<(TileType, Handle<Image>)>,
+    texture_ids: Option<Vec<(TileType, egui::TextureId)>>,
+}
+
+const THUMBNAIL_SIZE: u32 = 64;
+
+const TILE_ATLAS_PATH: &str = "tiles/atlas.png";
+
+/// The single sprite-sheet image shared by every `TileType`, each occupying
+/// one `TILE_SIZE` cell of `layout` selected by `TileType::atlas_index`, so
+/// tile variants batch into one draw call instead of one image each.
+#[derive(Resource)]
+struct TileAssets {
+    image: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+}
+
+impl TileAssets {
+    fn get(&self, tile_type: TileType) -> (Handle<Image>, TextureAtlas) {
+        (
+            self.image.clone(),
+            TextureAtlas {
+                layout: self.layout.clone(),
+                index: tile_type.atlas_index(),
+            },
+        )
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
 enum TileType {
     Grass,
@@ -27,6 +147,37 @@ enum TileType {
     Mountain,
 }
 
+impl TileType {
+    const ALL: [TileType; 3] = [TileType::Grass, TileType::Water, TileType::Mountain];
+
+    fn label(&self) -> &'static str {
+        match self {
+            TileType::Grass => "Grass",
+            TileType::Water => "Water",
+            TileType::Mountain => "Mountain",
+        }
+    }
+
+    /// Where this tile's art lives. Every variant currently shares one
+    /// packed sprite sheet (see `atlas_index`), but callers that just want
+    /// "the texture for this tile kind" still have one place to ask instead
+    /// of reaching for the `TILE_ATLAS_PATH` constant directly.
+    fn get_texture_path(&self) -> &'static str {
+        TILE_ATLAS_PATH
+    }
+
+    /// This tile's column in the shared `tiles/atlas.png` sprite sheet, so
+    /// every tile variant is packed into one image instead of loading a
+    /// separate texture per type.
+    fn atlas_index(&self) -> usize {
+        match self {
+            TileType::Grass => 0,
+            TileType::Water => 1,
+            TileType::Mountain => 2,
+        }
+    }
+}
+
 #[derive(Component, Serialize, Deserialize)]
 struct SerializableTile {
     x: i32,
@@ -35,14 +186,77 @@ struct SerializableTile {
 }
 
 const TILE_SIZE: f32 = 32.0;
-const VIEW_RADIUS: i32 = 60;
+
+/// Vertical distance between adjacent hex rows for pointy-top hexes.
+fn hex_row_height() -> f32 {
+    TILE_SIZE * 0.75
+}
+
+/// Maps a tile coordinate to its world-space center for the given `TileShape`.
+/// Hex rows are offset by half a tile on odd rows (odd-row offset coordinates).
+fn tile_to_world(shape: TileShape, col: i32, row: i32) -> Vec2 {
+    match shape {
+        TileShape::Square => Vec2::new(col as f32 * TILE_SIZE, row as f32 * TILE_SIZE),
+        TileShape::Hex => {
+            let col_offset = if row & 1 != 0 { 0.5 } else { 0.0 };
+            Vec2::new(
+                (col as f32 + col_offset) * TILE_SIZE,
+                row as f32 * hex_row_height(),
+            )
+        }
+    }
+}
+
+/// Inverse of `tile_to_world`: snaps a world-space position to the nearest
+/// tile coordinate. For hex mode this rounds to a candidate row, applies
+/// that row's parity offset, then picks the nearest of the neighboring
+/// hex centers since a naive per-axis round can pick the wrong hex.
+fn world_to_tile(shape: TileShape, world_pos: Vec2) -> (i32, i32) {
+    match shape {
+        TileShape::Square => (
+            (world_pos.x / TILE_SIZE).round() as i32,
+            (world_pos.y / TILE_SIZE).round() as i32,
+        ),
+        TileShape::Hex => {
+            let candidate_row = (world_pos.y / hex_row_height()).round() as i32;
+
+            let mut best_coord = (0, 0);
+            let mut best_dist = f32::MAX;
+            for row in (candidate_row - 1)..=(candidate_row + 1) {
+                let col_offset = if row & 1 != 0 { 0.5 } else { 0.0 };
+                let candidate_col = ((world_pos.x / TILE_SIZE) - col_offset).round() as i32;
+                for col in (candidate_col - 1)..=(candidate_col + 1) {
+                    let dist = tile_to_world(shape, col, row).distance_squared(world_pos);
+                    if dist < best_dist {
+                        best_dist = dist;
+                        best_coord = (col, row);
+                    }
+                }
+            }
+            best_coord
+        }
+    }
+}
 
 fn main() {
     App::new()
         .insert_resource(ClearColor(Color::srgb(1.0, 0.92, 0.9)))
         .insert_resource(TileMap::default())
+        .insert_resource(ChunkMap::default())
+        .insert_resource(NoiseGenerators {
+            simplex: Simplex::new(1000),
+            perlin: Perlin::new(1000),
+        })
         .insert_resource(EditorMode::Pan)
         .insert_resource(SelectedTileType(TileType::Grass))
+        .insert_resource(TileShape::Square)
+        // This is an editing tool, not an action game: stop continuously
+        // re-rendering and re-running tile streaming when nothing is happening.
+        // `PowerMode` drives the actual `WinitSettings` value via
+        // `apply_power_mode`, so this can be toggled back to continuous
+        // redraws at runtime instead of being a compile-time choice.
+        .insert_resource(PowerMode::Reactive)
+        .insert_resource(WinitSettings::desktop_app())
         .add_plugins((
             DefaultPlugins.set(WindowPlugin {
                 primary_window: Some(Window {
@@ -53,18 +267,29 @@ fn main() {
                 ..default()
             }),
             PanCamPlugin::default(),
+            EguiPlugin,
         ))
-        .add_systems(Startup, init_app)
+        .add_systems(Startup, (init_app, (load_tile_assets, spawn_tile_thumbnails).chain()))
         .add_systems(Update, (
-            update_tiles,
+            (update_tiles, paint_tiles, request_redraw).run_if(editor_is_active),
             toggle_mode,
-            paint_tiles,
             update_camera_control,
-            switch_tile_type,
+            tile_palette_ui,
+            toggle_tile_shape,
+            toggle_power_mode,
+            apply_power_mode,
+            save_map,
+            load_map,
         ))
         .run();
 }
 
+/// Marks the single editor camera, so queries that need "the" camera can
+/// filter out the offscreen `spawn_tile_thumbnails` cameras instead of
+/// tripping over `.single()` seeing more than one `Camera`.
+#[derive(Component)]
+struct MainCamera;
+
 fn init_app(mut commands: Commands) {
     commands.spawn((
         Camera2d,
@@ -79,7 +304,155 @@ fn init_app(mut commands: Commands) {
             enabled: true,
             ..default()
         },
+        MainCamera,
+    ));
+}
+
+/// Spawns one sample sprite per `TileType` on its own `RenderLayers` layer,
+/// each viewed by a dedicated camera that renders into an offscreen image.
+/// The resulting image handles are stashed in `TilePalette` for the egui
+/// panel to register as textures and draw as palette thumbnails. The
+/// sample sprite is textured from `TileAssets`, the same atlas crop
+/// `update_tiles` paints onto the map, so the thumbnail matches the tile.
+fn spawn_tile_thumbnails(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    tile_assets: Res<TileAssets>,
+) {
+    let mut thumbnails = Vec::new();
+
+    for (i, tile_type) in TileType::ALL.iter().enumerate() {
+        let size = Extent3d {
+            width: THUMBNAIL_SIZE,
+            height: THUMBNAIL_SIZE,
+            depth_or_array_layers: 1,
+        };
+
+        let mut image = Image::new_fill(
+            size,
+            TextureDimension::D2,
+            &[0, 0, 0, 0],
+            TextureFormat::Bgra8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        image.texture_descriptor.usage =
+            TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+        let image_handle = images.add(image);
+
+        let layer = RenderLayers::layer(i + 1);
+
+        let (tile_image, tile_atlas) = tile_assets.get(*tile_type);
+
+        commands.spawn((
+            Sprite {
+                image: tile_image,
+                texture_atlas: Some(tile_atlas),
+                custom_size: Some(Vec2::splat(TILE_SIZE)),
+                ..default()
+            },
+            Transform::default(),
+            layer.clone(),
+        ));
+
+        commands.spawn((
+            Camera2d,
+            Camera {
+                target: RenderTarget::Image(image_handle.clone()),
+                clear_color: ClearColorConfig::Custom(Color::NONE),
+                order: -(i as isize) - 1,
+                ..default()
+            },
+            layer,
+        ));
+
+        thumbnails.push((*tile_type, image_handle));
+    }
+
+    commands.insert_resource(TilePalette {
+        thumbnails,
+        texture_ids: None,
+    });
+}
+
+/// Loads the shared tile atlas image and its grid layout, so `update_tiles`
+/// spawns textured sprites instead of flat `Color::srgb` swatches.
+fn load_tile_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    // Every `TileType` currently shares the same packed sheet, so any
+    // variant's `get_texture_path` points at it.
+    let image = asset_server.load(TileType::Grass.get_texture_path());
+    let layout = layouts.add(TextureAtlasLayout::from_grid(
+        UVec2::splat(TILE_SIZE as u32),
+        TileType::ALL.len() as u32,
+        1,
+        None,
+        None,
     ));
+
+    commands.insert_resource(TileAssets { image, layout });
+}
+
+/// Docked egui panel listing each `TileType` as a clickable thumbnail
+/// button, replacing the old digit-key `switch_tile_type` workflow.
+fn tile_palette_ui(
+    mut contexts: EguiContexts,
+    mut palette: ResMut<TilePalette>,
+    mut selected: ResMut<SelectedTileType>,
+) {
+    if palette.texture_ids.is_none() {
+        let registered = palette
+            .thumbnails
+            .iter()
+            .map(|(tile_type, handle)| (*tile_type, contexts.add_image(handle.clone())))
+            .collect();
+        palette.texture_ids = Some(registered);
+    }
+
+    egui::SidePanel::left("tile_palette").show(contexts.ctx_mut(), |ui| {
+        ui.heading("Tiles");
+        for (tile_type, texture_id) in palette.texture_ids.as_ref().unwrap() {
+            let button = egui::ImageButton::new(egui::load::SizedTexture::new(
+                *texture_id,
+                egui::vec2(THUMBNAIL_SIZE as f32, THUMBNAIL_SIZE as f32),
+            ))
+            .selected(selected.0 == *tile_type);
+
+            if ui.add(button).on_hover_text(tile_type.label()).clicked() {
+                selected.0 = *tile_type;
+                info!("Switched to {}", tile_type.label());
+            }
+        }
+    });
+}
+
+/// Run condition gating tile-streaming and paint work in desktop-app power
+/// mode: true while the camera is panning/zooming, on the frame a key or
+/// mouse button is pressed, while the left mouse button is held, or when the
+/// editor mode/grid shape just changed. False on an idle frame,
+/// so `update_tiles`/`paint_tiles` don't run just to find nothing to do.
+fn editor_is_active(
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    mode: Res<EditorMode>,
+    shape: Res<TileShape>,
+    camera_q: Query<(), (With<Camera>, Changed<Transform>)>,
+) -> bool {
+    !camera_q.is_empty()
+        || keys.get_just_pressed().next().is_some()
+        || buttons.get_just_pressed().next().is_some()
+        || buttons.pressed(MouseButton::Left)
+        || mode.is_changed()
+        || shape.is_changed()
+}
+
+/// Explicitly asks winit for another frame while the editor is active, since
+/// `WinitSettings::desktop_app()` otherwise only redraws in response to OS
+/// window events and would leave panning/painting looking stuck.
+fn request_redraw(mut redraw: EventWriter<RequestRedraw>) {
+    redraw.send(RequestRedraw);
 }
 
 fn toggle_mode(
@@ -95,6 +468,46 @@ fn toggle_mode(
     }
 }
 
+fn toggle_tile_shape(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut shape: ResMut<TileShape>,
+) {
+    if keys.just_pressed(KeyCode::KeyG) {
+        *shape = match *shape {
+            TileShape::Square => TileShape::Hex,
+            TileShape::Hex => TileShape::Square,
+        };
+        info!("Switched to {:?} grid", *shape);
+    }
+}
+
+fn toggle_power_mode(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut power_mode: ResMut<PowerMode>,
+) {
+    if keys.just_pressed(KeyCode::KeyP) {
+        *power_mode = match *power_mode {
+            PowerMode::Reactive => PowerMode::Continuous,
+            PowerMode::Continuous => PowerMode::Reactive,
+        };
+        info!("Switched to {:?} power mode", *power_mode);
+    }
+}
+
+/// Applies `PowerMode` to the real `WinitSettings` resource whenever it
+/// changes, so toggling it at runtime takes effect immediately instead of
+/// only being read once at startup.
+fn apply_power_mode(power_mode: Res<PowerMode>, mut winit_settings: ResMut<WinitSettings>) {
+    if !power_mode.is_changed() {
+        return;
+    }
+
+    *winit_settings = match *power_mode {
+        PowerMode::Reactive => WinitSettings::desktop_app(),
+        PowerMode::Continuous => WinitSettings::game(),
+    };
+}
+
 fn update_camera_control(
     mode: Res<EditorMode>,
     mut query: Query<&mut PanCam>,
@@ -105,36 +518,74 @@ fn update_camera_control(
     }
 }
 
-fn switch_tile_type(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut selected: ResMut<SelectedTileType>,
+/// Despawns whatever entity currently occupies `coord` (if any) and spawns
+/// a fresh tile sprite in its place, keeping `TileMap::entities` and the
+/// owning `ChunkState` in sync. Shared by `paint_tiles` (immediate repaint)
+/// and `load_map` (respawning tiles read back from disk).
+fn spawn_or_replace_tile(
+    commands: &mut Commands,
+    tile_assets: &TileAssets,
+    tile_map: &mut TileMap,
+    chunk_map: &mut ChunkMap,
+    shape: TileShape,
+    coord: (i32, i32),
+    tile_type: TileType,
 ) {
-    if keys.just_pressed(KeyCode::Digit1) {
-        selected.0 = TileType::Grass;
-        info!("Switched to Grass");
-    } else if keys.just_pressed(KeyCode::Digit2) {
-        selected.0 = TileType::Water;
-        info!("Switched to Water");
-    } else if keys.just_pressed(KeyCode::Digit3) {
-        selected.0 = TileType::Mountain;
-        info!("Switched to Mountain");
+    if let Some(entity) = tile_map.entities.remove(&coord) {
+        commands.entity(entity).despawn();
+        if let Some(state) = chunk_map.loaded.get_mut(&chunk_coord(coord)) {
+            state.entities.retain(|e| *e != entity);
+        }
+    }
+
+    let (image, atlas) = tile_assets.get(tile_type);
+    let world_pos = tile_to_world(shape, coord.0, coord.1);
+    let entity = commands
+        .spawn((
+            Sprite {
+                image,
+                texture_atlas: Some(atlas),
+                custom_size: Some(Vec2::splat(TILE_SIZE - 0.1)),
+                ..default()
+            },
+            Transform::from_xyz(world_pos.x, world_pos.y, 0.0),
+            SerializableTile {
+                x: coord.0,
+                y: coord.1,
+                tile_type,
+            },
+        ))
+        .id();
+
+    tile_map.entities.insert(coord, entity);
+    if let Some(state) = chunk_map.loaded.get_mut(&chunk_coord(coord)) {
+        state.entities.push(entity);
     }
 }
 
 fn paint_tiles(
     windows: Query<&Window>,
-    camera_q: Query<(&Camera, &GlobalTransform)>,
+    camera_q: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     buttons: Res<ButtonInput<MouseButton>>,
     mode: Res<EditorMode>,
+    shape: Res<TileShape>,
+    tile_assets: Res<TileAssets>,
     mut tile_map: ResMut<TileMap>,
+    mut chunk_map: ResMut<ChunkMap>,
     selected_tile: Res<SelectedTileType>,
     mut commands: Commands,
-    tiles_query: Query<(Entity, &SerializableTile)>,
+    mut contexts: EguiContexts,
 ) {
     if *mode != EditorMode::Paint {
         return;
     }
 
+    // Don't paint through the egui palette panel: a click on a thumbnail
+    // button also reads as a left-mouse-button press at that screen position.
+    if contexts.ctx_mut().wants_pointer_input() {
+        return;
+    }
+
     // Handle potential query errors
     let Ok(window) = windows.single() else { return };
     let Ok((camera, camera_transform)) = camera_q.single() else { return };
@@ -142,87 +593,194 @@ fn paint_tiles(
     if let Some(screen_pos) = window.cursor_position() {
         if buttons.pressed(MouseButton::Left) {
             if let Ok(world_pos) = camera.viewport_to_world(camera_transform, screen_pos) {
-                let world_pos = world_pos;
-
-                let tile_x = (world_pos.origin.x / TILE_SIZE).round() as i32;
-                let tile_y = (world_pos.origin.y / TILE_SIZE).round() as i32;
-                let coord = (tile_x, tile_y);
+                let coord = world_to_tile(*shape, world_pos.origin.truncate());
 
                 // Paint the tile
                 tile_map.tiles.insert(coord, selected_tile.0);
+                tile_map.painted.insert(coord);
 
-                // Despawn old tile if it exists
-                if let Some((entity, _)) = tiles_query.iter().find(|(_, tile)| (tile.x, tile.y) == coord) {
-                    commands.entity(entity).despawn();
-                    tile_map.spawned.remove(&coord);
-                }
+                // Respawn it immediately: `update_tiles` only streams chunks
+                // when the camera crosses into a new one, so a painted tile
+                // in the current chunk wouldn't otherwise get redrawn.
+                spawn_or_replace_tile(
+                    &mut commands,
+                    &tile_assets,
+                    &mut tile_map,
+                    &mut chunk_map,
+                    *shape,
+                    coord,
+                    selected_tile.0,
+                );
             }
         }
     }
 }
 
+/// Writes every explicitly-painted tile to `map.ron` on Ctrl+S, so the
+/// procedurally-generated world around it never gets dumped to disk.
+fn save_map(keys: Res<ButtonInput<KeyCode>>, tile_map: Res<TileMap>) {
+    let ctrl_pressed = keys.pressed(KeyCode::ControlLeft) || keys.pressed(KeyCode::ControlRight);
+    if !(ctrl_pressed && keys.just_pressed(KeyCode::KeyS)) {
+        return;
+    }
+
+    let tiles: Vec<SerializableTile> = tile_map
+        .painted
+        .iter()
+        .filter_map(|coord| {
+            tile_map.tiles.get(coord).map(|tile_type| SerializableTile {
+                x: coord.0,
+                y: coord.1,
+                tile_type: *tile_type,
+            })
+        })
+        .collect();
+
+    match ron::to_string(&tiles) {
+        Ok(serialized) => match std::fs::write(MAP_SAVE_PATH, serialized) {
+            Ok(()) => info!("Saved {} painted tiles to {}", tiles.len(), MAP_SAVE_PATH),
+            Err(err) => error!("Failed to write {}: {}", MAP_SAVE_PATH, err),
+        },
+        Err(err) => error!("Failed to serialize map: {}", err),
+    }
+}
+
+/// Reads `map.ron` back on Ctrl+O, repopulating `TileMap::tiles` and
+/// respawning sprites for the tiles it describes.
+fn load_map(
+    keys: Res<ButtonInput<KeyCode>>,
+    shape: Res<TileShape>,
+    tile_assets: Res<TileAssets>,
+    mut tile_map: ResMut<TileMap>,
+    mut chunk_map: ResMut<ChunkMap>,
+    mut commands: Commands,
+) {
+    let ctrl_pressed = keys.pressed(KeyCode::ControlLeft) || keys.pressed(KeyCode::ControlRight);
+    if !(ctrl_pressed && keys.just_pressed(KeyCode::KeyO)) {
+        return;
+    }
+
+    let contents = match std::fs::read_to_string(MAP_SAVE_PATH) {
+        Ok(contents) => contents,
+        Err(err) => {
+            error!("Failed to read {}: {}", MAP_SAVE_PATH, err);
+            return;
+        }
+    };
+
+    let tiles: Vec<SerializableTile> = match ron::from_str(&contents) {
+        Ok(tiles) => tiles,
+        Err(err) => {
+            error!("Failed to parse {}: {}", MAP_SAVE_PATH, err);
+            return;
+        }
+    };
+
+    let tile_count = tiles.len();
+    for tile in tiles {
+        let coord = (tile.x, tile.y);
+        tile_map.tiles.insert(coord, tile.tile_type);
+        tile_map.painted.insert(coord);
+
+        // Only respawn tiles whose chunk is actually loaded right now.
+        // Spawning unconditionally would add an entity to `tile_map.entities`
+        // with no owning `ChunkState`, so chunk-unload would never despawn
+        // it; `update_tiles` would then spawn a second entity for the same
+        // coordinate once the camera streamed that chunk in, orphaning the
+        // first. Tiles in chunks that aren't loaded yet get picked up from
+        // `tile_map.tiles` the normal way when `update_tiles` streams them in.
+        if chunk_map.loaded.contains_key(&chunk_coord(coord)) {
+            spawn_or_replace_tile(
+                &mut commands,
+                &tile_assets,
+                &mut tile_map,
+                &mut chunk_map,
+                *shape,
+                coord,
+                tile.tile_type,
+            );
+        }
+    }
+
+    info!("Loaded {} tiles from {}", tile_count, MAP_SAVE_PATH);
+}
+
 fn update_tiles(
     mut commands: Commands,
-    cam_query: Query<&Transform, With<Camera>>,
+    cam_query: Query<&Transform, With<MainCamera>>,
+    shape: Res<TileShape>,
+    tile_assets: Res<TileAssets>,
+    noise: Res<NoiseGenerators>,
     mut tile_map: ResMut<TileMap>,
-    tiles_query: Query<(Entity, &Transform), With<SerializableTile>>,
+    mut chunk_map: ResMut<ChunkMap>,
 ) {
+    let shape = *shape;
     let cam_pos = cam_query.single().map_or(vec3(0.0, 0.0, -5.0), |t| t.translation);
-    let center_x = (cam_pos.x / TILE_SIZE).round() as i32;
-    let center_y = (cam_pos.y / TILE_SIZE).round() as i32;
+    let camera_chunk = chunk_coord(world_to_tile(shape, cam_pos.truncate()));
 
-    let visible_tiles: HashSet<(i32, i32)> = ((center_y - VIEW_RADIUS)..=(center_y + VIEW_RADIUS))
-        .flat_map(|y| (center_x - VIEW_RADIUS..=center_x + VIEW_RADIUS).map(move |x| (x, y)))
-        .collect();
+    if chunk_map.last_camera_chunk == Some(camera_chunk) {
+        return;
+    }
+    chunk_map.last_camera_chunk = Some(camera_chunk);
+
+    let visible_chunks = visible_chunk_coords(camera_chunk, CHUNK_RADIUS);
 
-    for (entity, transform) in tiles_query.iter() {
-        let tile_x = (transform.translation.x / TILE_SIZE).round() as i32;
-        let tile_y = (transform.translation.y / TILE_SIZE).round() as i32;
-        let coord = (tile_x, tile_y);
+    let chunks_to_unload: Vec<(i32, i32)> = chunk_map
+        .loaded
+        .keys()
+        .filter(|chunk| !visible_chunks.contains(*chunk))
+        .copied()
+        .collect();
 
-        if !visible_tiles.contains(&coord) {
+    for chunk in chunks_to_unload {
+        let Some(state) = chunk_map.loaded.remove(&chunk) else { continue };
+        for entity in state.entities {
             commands.entity(entity).despawn();
-            tile_map.spawned.remove(&coord);
+        }
+        for coord in chunk_tile_coords(chunk) {
+            tile_map.entities.remove(&coord);
         }
     }
 
-    let simplex = Simplex::new(1000);
-    let perlin = Perlin::new(1000);
-
-    for &(x, y) in &visible_tiles {
-        if tile_map.spawned.contains(&(x, y)) {
+    for chunk in visible_chunks {
+        if chunk_map.loaded.contains_key(&chunk) {
             continue;
         }
 
-        let tile_type = tile_map.tiles.entry((x, y)).or_insert_with(|| {
-            let noise = (simplex.get([x as f64 / 10.0, y as f64 / 10.0]) + perlin.get([x as f64 / 10.0, y as f64 / 10.0])) / 2.0;
-            match noise {
-                n if n < -0.2 => TileType::Water,
-                n if n < 0.4 => TileType::Grass,
-                _ => TileType::Mountain,
-            }
-        });
+        let mut entities = Vec::with_capacity((CHUNK_SIZE * CHUNK_SIZE) as usize);
 
-        let color = match tile_type {
-            TileType::Grass => Color::srgb(0.3, 1.0, 0.3),
-            TileType::Water => Color::srgb(0.0, 0.3, 1.0),
-            TileType::Mountain => Color::srgb(0.3, 0.3, 0.3),
-        };
+        for (x, y) in chunk_tile_coords(chunk) {
+            let tile_type = *tile_map.tiles.entry((x, y)).or_insert_with(|| {
+                let n = (noise.simplex.get([x as f64 / 10.0, y as f64 / 10.0])
+                    + noise.perlin.get([x as f64 / 10.0, y as f64 / 10.0]))
+                    / 2.0;
+                match n {
+                    n if n < -0.2 => TileType::Water,
+                    n if n < 0.4 => TileType::Grass,
+                    _ => TileType::Mountain,
+                }
+            });
 
-        commands.spawn((
-            Sprite {
-                color,
-                custom_size: Some(Vec2::splat(TILE_SIZE - 0.1)),
-                ..default()
-            },
-            Transform::from_xyz(x as f32 * TILE_SIZE, y as f32 * TILE_SIZE, 0.0),
-            SerializableTile {
-                x,
-                y,
-                tile_type: *tile_type,
-            },
-        ));
+            let (image, atlas) = tile_assets.get(tile_type);
+            let world_pos = tile_to_world(shape, x, y);
+
+            let entity = commands
+                .spawn((
+                    Sprite {
+                        image,
+                        texture_atlas: Some(atlas),
+                        custom_size: Some(Vec2::splat(TILE_SIZE - 0.1)),
+                        ..default()
+                    },
+                    Transform::from_xyz(world_pos.x, world_pos.y, 0.0),
+                    SerializableTile { x, y, tile_type },
+                ))
+                .id();
+
+            entities.push(entity);
+            tile_map.entities.insert((x, y), entity);
+        }
 
-        tile_map.spawned.insert((x, y));
+        chunk_map.loaded.insert(chunk, ChunkState { entities });
     }
 }
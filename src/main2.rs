@@ -17,188 +17,301 @@ struct PerspCamera;
 
 #[derive(Default, Resource)]
 struct TileMap {
-    tiles: HashMap<(i32, i32), TileType>,
-    spawned: HashSet<(i32, i32)>,
+    tiles: HashMap<(i32, i32), TileType>,
+    spawned: HashSet<(i32, i32)>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
 enum TileType {
-    Grass,
-    Water,
-    Mountain,
+    Grass,
+    Water,
+    Mountain,
+}
+
+impl TileType {
+    const ALL: [TileType; 3] = [TileType::Grass, TileType::Water, TileType::Mountain];
+
+    fn color(&self) -> Color {
+        match self {
+            TileType::Grass => Color::srgb(0.3, 1.0, 0.3),
+            TileType::Water => Color::srgb(0.0, 0.3, 1.0),
+            TileType::Mountain => Color::srgb(0.3, 0.3, 0.3),
+        }
+    }
+
+    /// Height of the tile's extruded 3D terrain block, so the perspective
+    /// camera sees real topography instead of a flat plane of sprites.
+    fn terrain_height(&self) -> f32 {
+        match self {
+            TileType::Water => TILE_SIZE * 0.2,
+            TileType::Grass => TILE_SIZE * 0.5,
+            TileType::Mountain => TILE_SIZE * 2.0,
+        }
+    }
+
+    /// Extra vertical offset so water sits slightly sunken relative to land.
+    fn terrain_y_offset(&self) -> f32 {
+        match self {
+            TileType::Water => -TILE_SIZE * 0.15,
+            _ => 0.0,
+        }
+    }
 }
 
 #[derive(Component, Serialize, Deserialize)]
 struct SerializableTile {
-    x: i32,
-    y: i32,
-    tile_type: TileType,
+    x: i32,
+    y: i32,
+    tile_type: TileType,
+}
+
+/// The 3D mesh/material pair for each `TileType`'s extruded terrain block,
+/// built once at startup so `update_tiles` only has to look them up.
+#[derive(Resource)]
+struct TerrainAssets {
+    variants: Vec<(TileType, Handle<Mesh>, Handle<StandardMaterial>)>,
+}
+
+impl TerrainAssets {
+    fn get(&self, tile_type: TileType) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+        self.variants
+            .iter()
+            .find(|(t, _, _)| *t == tile_type)
+            .map(|(_, mesh, material)| (mesh.clone(), material.clone()))
+            .expect("TerrainAssets covers every TileType")
+    }
+}
+
+/// Which camera is currently active, in cycling order.
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+enum CameraKind {
+    Ortho,
+    Persp,
+}
+
+impl CameraKind {
+    const ALL: [CameraKind; 2] = [CameraKind::Ortho, CameraKind::Persp];
+
+    fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|kind| *kind == self).unwrap();
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
 }
 
 const TILE_SIZE: f32 = 32.0;
 const VIEW_RADIUS: i32 = 60;
 
 fn main() {
-    App::new()
-        .insert_resource(ClearColor(Color::srgb(0.9, 0.92, 1.0)))
-        .insert_resource(TileMap::default())
-        .add_plugins((
-            DefaultPlugins.set(WindowPlugin {
-                primary_window: Some(Window {
-                    resolution: (800., 600.).into(),
-                    title: "Tile Map!".into(),
-                    ..default()
-                }),
-                ..default()
-            }),
-            PanCamPlugin::default(),
-        ))
-        .add_systems(Startup, init_app) // Use our modified init_app
-        // Add both systems to Update
-        .add_systems(Update, (update_tiles, toggle_camera_projection))
-        .run();
+    App::new()
+        .insert_resource(ClearColor(Color::srgb(0.9, 0.92, 1.0)))
+        .insert_resource(TileMap::default())
+        .insert_resource(CameraKind::Ortho)
+        .add_plugins((
+            DefaultPlugins.set(WindowPlugin {
+                primary_window: Some(Window {
+                    resolution: (800., 600.).into(),
+                    title: "Tile Map!".into(),
+                    ..default()
+                }),
+                ..default()
+            }),
+            PanCamPlugin::default(),
+        ))
+        .add_systems(Startup, (init_app, spawn_terrain_assets)) // Use our modified init_app
+        // Add both systems to Update
+        .add_systems(Update, (update_tiles, cycle_camera))
+        .run();
 }
 
 // Modified init_app to set up both cameras
 fn init_app(mut commands: Commands) {
-    // Spawn the 2D Orthographic Camera with PanCam
-    // This one is active by default
-    commands.spawn((
-        Camera2d, // Use the bundle
-        PanCam {
-            grab_buttons: vec![MouseButton::Left, MouseButton::Middle],
-            move_keys: DirectionKeys {
-                up:     vec![KeyCode::KeyW],
-                down:   vec![KeyCode::KeyS],
-                left:   vec![KeyCode::KeyA],
-                right:  vec![KeyCode::KeyD],
-            },
-            speed: 300.,
-            enabled: true, // PanCam is enabled for the Ortho camera
-            zoom_to_cursor: true,
-            min_scale: 1.,
-            max_scale: 4.,
-            min_x: f32::NEG_INFINITY,
-            max_x: f32::INFINITY,
-            min_y: f32::NEG_INFINITY,
-            max_y: f32::INFINITY,
-        },
-        OrthoCamera, // Add the marker
-    ));
-
-    // Spawn the 3D Perspective Camera
-    // Position it looking down and slightly forward
-    let camera_pos_3d = vec3(0.0, -50.0, 100.0); // Example starting position
-    let camera_look_at_3d = vec3(0.0, 0.0, 0.0); // Example point to look at
-    commands.spawn((
-        Camera3d {
-            transform: Transform::from_translation(camera_pos_3d)
-                .looking_at(camera_look_at_3d, Vec3::Y),
-            ..default()
-        },
-        PerspCamera, // Add the marker
-    ));
+    // Spawn the 2D Orthographic Camera with PanCam
+    // This one is active by default
+    commands.spawn((
+        Camera2d, // Use the bundle
+        PanCam {
+            grab_buttons: vec![MouseButton::Left, MouseButton::Middle],
+            move_keys: DirectionKeys {
+                up:     vec![KeyCode::KeyW],
+                down:   vec![KeyCode::KeyS],
+                left:   vec![KeyCode::KeyA],
+                right:  vec![KeyCode::KeyD],
+            },
+            speed: 300.,
+            enabled: true, // PanCam is enabled for the Ortho camera
+            zoom_to_cursor: true,
+            min_scale: 1.,
+            max_scale: 4.,
+            min_x: f32::NEG_INFINITY,
+            max_x: f32::INFINITY,
+            min_y: f32::NEG_INFINITY,
+            max_y: f32::INFINITY,
+        },
+        OrthoCamera, // Add the marker
+    ));
+
+    // Spawn the 3D Perspective Camera
+    // Position it looking down and slightly forward
+    let camera_pos_3d = vec3(0.0, 100.0, -50.0); // Example starting position
+    let camera_look_at_3d = vec3(0.0, 0.0, 0.0); // Example point to look at
+    commands.spawn((
+        Camera3d::default(),
+        Camera {
+            is_active: false,
+            ..default()
+        },
+        Transform::from_translation(camera_pos_3d).looking_at(camera_look_at_3d, Vec3::Y),
+        PerspCamera, // Add the marker
+    ));
+
+    // The 3D terrain needs a light to be visible under the perspective camera.
+    commands.spawn((
+        DirectionalLight {
+            illuminance: 5000.0,
+            ..default()
+        },
+        Transform::from_xyz(200.0, 400.0, 200.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
 }
 
-// System to toggle camera activity
-fn toggle_camera_projection(
-  mut query_ortho: Query<(&mut Visibility, Option<&mut PanCam>), With<OrthoCamera>>,
-  mut query_persp: Query<&mut Visibility, With<PerspCamera>>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+/// Builds the extruded terrain mesh/material for each `TileType` once, so
+/// streamed tiles can just clone the handles instead of re-creating assets.
+fn spawn_terrain_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::KeyP) { // Choose your toggle key
-        let (mut ortho_visibility, mut pancam_option) = query_ortho.single_mut();
-        let mut persp_visibility = query_persp.single_mut();
-ortho_visibility.is_visible = !ortho_visibility.is_visible;
-persp_visibility.is_visible = !persp_visibility.is_visible;
+    let variants = TileType::ALL
+        .iter()
+        .map(|tile_type| {
+            let mesh = meshes.add(Cuboid::new(TILE_SIZE, tile_type.terrain_height(), TILE_SIZE));
+            let material = materials.add(StandardMaterial::from(tile_type.color()));
+            (*tile_type, mesh, material)
+        })
+        .collect();
 
+    commands.insert_resource(TerrainAssets { variants });
+}
 
-        if let Some(mut pancam) = pancam_option {
-            pancam.enabled = ortho_visibility.is_visible
-        ;
-        }
+/// Cycles through the available cameras on `C`, toggling both cameras'
+/// `Camera::is_active` and handing PanCam control to whichever is active.
+/// Replaces the old two-camera `toggle_camera_projection`/`P` toggle. The
+/// cycling order comes from `CameraKind::ALL`, but each kind's camera is
+/// matched by its own marker query here, so a new kind also needs its own
+/// marker, query and `is_active` line in this system.
+fn cycle_camera(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut active: ResMut<CameraKind>,
+    mut ortho_query: Query<(&mut Camera, Option<&mut PanCam>), (With<OrthoCamera>, Without<PerspCamera>)>,
+    mut persp_query: Query<&mut Camera, (With<PerspCamera>, Without<OrthoCamera>)>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::KeyC) {
+        return;
+    }
+
+    *active = active.next();
+
+    let Ok((mut ortho_camera, pancam_option)) = ortho_query.single_mut() else { return };
+    let Ok(mut persp_camera) = persp_query.single_mut() else { return };
+
+    ortho_camera.is_active = *active == CameraKind::Ortho;
+    persp_camera.is_active = *active == CameraKind::Persp;
 
-        // Optional: You might want to reset the perspective camera's position
-        // or sync it somehow with the 2D view position when toggling.
-        // For simplicity, this example just swaps.
-    }
+    if let Some(mut pancam) = pancam_option {
+        pancam.enabled = *active == CameraKind::Ortho;
+    }
+
+    info!("Switched to {:?} camera", *active);
 }
 
 
 fn update_tiles(
-    mut commands: Commands,
-    // Query specifically for the OrthoCamera's transform (assuming it drives tile spawning)
-    // We assume the toggle system ensures the OrthoCamera is active when we want to update tiles based on its view.
-    cam_query: Query<&Transform, With<OrthoCamera>>,
-    mut tile_map: ResMut<TileMap>,
-    tiles_query: Query<(Entity, &Transform), With<SerializableTile>>,
+    mut commands: Commands,
+    // Query specifically for the OrthoCamera's transform (assuming it drives tile spawning)
+    // We assume the toggle system ensures the OrthoCamera is active when we want to update tiles based on its view.
+    cam_query: Query<&Transform, With<OrthoCamera>>,
+    terrain_assets: Res<TerrainAssets>,
+    mut tile_map: ResMut<TileMap>,
+    tiles_query: Query<(Entity, &SerializableTile)>,
 ) {
-    // Use .single() directly as we expect only one OrthoCamera
-    let cam_transform = cam_query.single();
-    let cam_pos = cam_transform.unwrap().translation;
-
-    let center_x = (cam_pos.x / TILE_SIZE).round() as i32;
-    let center_y = (cam_pos.y / TILE_SIZE).round() as i32;
-
-    let visible_tiles: HashSet<(i32, i32)> = ((center_y - VIEW_RADIUS)..=(center_y + VIEW_RADIUS))
-        .flat_map(|y| {
-            (center_x - VIEW_RADIUS..=center_x + VIEW_RADIUS)
-                .map(move |x| (x, y))
-        })
-        .collect();
-
-    for (entity, transform) in tiles_query.iter() {
-        let tile_x = (transform.translation.x / TILE_SIZE).round() as i32;
-        let tile_y = (transform.translation.y / TILE_SIZE).round() as i32;
-        let coord = (tile_x, tile_y);
-
-        if !visible_tiles.contains(&coord) {
-            commands.entity(entity).despawn();
-            tile_map.spawned.remove(&coord);
-        }
-    }
-
-    let simplex = Simplex::new(1000);
-    let perlin = Perlin::new(1000);
-    for &(x, y) in &visible_tiles {
-        if tile_map.spawned.contains(&(x, y)) {
-            continue;
-        }
-
-        let noise = (simplex.get([x as f64 / 10.0, y as f64 / 10.0]) + perlin.get([x as f64 / 10.0, y as f64 / 10.0]))/2.;
-        let tile_type = tile_map.tiles.entry((x, y)).or_insert_with(|| {
-            match noise {
-                n if n < -0.2 => TileType::Water,
-                n if n < 0.4 => TileType::Grass,
-                _ => TileType::Mountain,
-            }
-        });
-
-        let color = match tile_type {
-            TileType::Grass => Color::srgb(0.3, 1.0, 0.3),
-            TileType::Water => Color::srgb(0.0, 0.3, 1.0),
-            TileType::Mountain => Color::srgb(0.3, 0.3, 0.3),
-        };
-
-        commands.spawn((
-            Sprite {
-                color,
-                // FIX: Make size slightly larger to prevent lines
-                custom_size: Some(Vec2::splat(TILE_SIZE + 0.1)),
-                ..default()
-            },
-            Transform::from_xyz(
-                x as f32 * TILE_SIZE,
-                y as f32 * TILE_SIZE,
-                0.0, // Z is 0 for 2D sprites
-            ),
-            SerializableTile {
-                x,
-                y,
-                tile_type: *tile_type,
-            }
-        ));
-
-        tile_map.spawned.insert((x, y));
-    }
-}
\ No newline at end of file
+    // Use .single() directly as we expect only one OrthoCamera
+    let cam_transform = cam_query.single();
+    let cam_pos = cam_transform.unwrap().translation;
+
+    let center_x = (cam_pos.x / TILE_SIZE).round() as i32;
+    let center_y = (cam_pos.y / TILE_SIZE).round() as i32;
+
+    let visible_tiles: HashSet<(i32, i32)> = ((center_y - VIEW_RADIUS)..=(center_y + VIEW_RADIUS))
+        .flat_map(|y| {
+            (center_x - VIEW_RADIUS..=center_x + VIEW_RADIUS)
+                .map(move |x| (x, y))
+        })
+        .collect();
+
+    for (entity, tile) in tiles_query.iter() {
+        let coord = (tile.x, tile.y);
+
+        if !visible_tiles.contains(&coord) {
+            commands.entity(entity).despawn();
+            tile_map.spawned.remove(&coord);
+        }
+    }
+
+    let simplex = Simplex::new(1000);
+    let perlin = Perlin::new(1000);
+    for &(x, y) in &visible_tiles {
+        if tile_map.spawned.contains(&(x, y)) {
+            continue;
+        }
+
+        let noise = (simplex.get([x as f64 / 10.0, y as f64 / 10.0]) + perlin.get([x as f64 / 10.0, y as f64 / 10.0]))/2.;
+        let tile_type = tile_map.tiles.entry((x, y)).or_insert_with(|| {
+            match noise {
+                n if n < -0.2 => TileType::Water,
+                n if n < 0.4 => TileType::Grass,
+                _ => TileType::Mountain,
+            }
+        });
+
+        let color = tile_type.color();
+
+        commands.spawn((
+            Sprite {
+                color,
+                // FIX: Make size slightly larger to prevent lines
+                custom_size: Some(Vec2::splat(TILE_SIZE + 0.1)),
+                ..default()
+            },
+            Transform::from_xyz(
+                x as f32 * TILE_SIZE,
+                y as f32 * TILE_SIZE,
+                0.0, // Z is 0 for 2D sprites
+            ),
+            SerializableTile {
+                x,
+                y,
+                tile_type: *tile_type,
+            }
+        ));
+
+        // Extrude matching 3D terrain under the perspective camera, so it
+        // shows real topography instead of looking at an empty scene.
+        let (mesh, material) = terrain_assets.get(*tile_type);
+        commands.spawn((
+            Mesh3d(mesh),
+            MeshMaterial3d(material),
+            Transform::from_xyz(
+                x as f32 * TILE_SIZE,
+                tile_type.terrain_height() / 2.0 + tile_type.terrain_y_offset(),
+                y as f32 * TILE_SIZE,
+            ),
+            SerializableTile {
+                x,
+                y,
+                tile_type: *tile_type,
+            },
+        ));
+
+        tile_map.spawned.insert((x, y));
+    }
+}